@@ -154,12 +154,14 @@ impl<T> ExternalPtr<T> {
 impl<T> Deref for ExternalPtr<T> {
     type Target = T;
     fn deref(&self) -> &Self::Target {
+        debug_assert!(!self.0.is_null(), "dereferencing a null ExternalPtr");
         unsafe { &*self.0 }
     }
 }
 
 impl<T> DerefMut for ExternalPtr<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
+        debug_assert!(!self.0.is_null(), "dereferencing a null ExternalPtr");
         unsafe { &mut *self.0 }
     }
 }
@@ -814,3 +816,11 @@ fn test_lisp_misc_any_size() {
     // Should be 32 bits, which is 4 bytes.
     assert!(mem::size_of::<Lisp_Misc_Any>() == 4);
 }
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn test_external_ptr_deref_null() {
+    let p = ExternalPtr::<i32>::new(std::ptr::null_mut());
+    assert_eq!(*p, 0);
+}